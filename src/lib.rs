@@ -0,0 +1,13 @@
+// Copyright 2021, 2022, 2023 Martin Pool
+
+//! `cargo-mutants`: find test gaps by injecting mutations into the source and
+//! checking whether the test suite still passes.
+
+mod mutant;
+pub mod visit;
+
+pub use mutant::{Mutant, MutationOp, SourceFile, Span};
+pub use visit::{discover_mutants, discover_mutants_with, NumericBoundaries};
+
+/// The crate-wide result type.
+pub type Result<T> = anyhow::Result<T>;
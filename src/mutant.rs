@@ -0,0 +1,172 @@
+// Copyright 2021, 2022, 2023 Martin Pool
+
+//! A mutant: a single change to a source file, the operation that produced it,
+//! and how that operation lowers to replacement source.
+
+use std::fmt;
+use std::sync::Arc;
+
+use proc_macro2::{LineColumn, Span as SynSpan};
+
+/// A region of a source file, as a start and end line/column.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span {
+    pub start: LineColumn,
+    pub end: LineColumn,
+}
+
+impl From<&SynSpan> for Span {
+    fn from(span: &SynSpan) -> Self {
+        Span {
+            start: span.start(),
+            end: span.end(),
+        }
+    }
+}
+
+/// The source of a single file being mutated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceFile {
+    /// The full source text of the file.
+    pub code: String,
+}
+
+/// One potential mutation: replace the text covered by `span` with the
+/// replacement produced by `op`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mutant {
+    pub source_file: Arc<SourceFile>,
+    pub op: MutationOp,
+    pub function_name: Arc<String>,
+    pub return_type: Arc<String>,
+    pub span: Span,
+}
+
+impl Mutant {
+    pub fn new(
+        source_file: Arc<SourceFile>,
+        op: MutationOp,
+        function_name: Arc<String>,
+        return_type: Arc<String>,
+        span: Span,
+    ) -> Mutant {
+        Mutant {
+            source_file,
+            op,
+            function_name,
+            return_type,
+            span,
+        }
+    }
+
+    /// The source text to splice in place of [`Mutant::span`].
+    pub fn replacement(&self) -> String {
+        self.op.replacement()
+    }
+}
+
+impl fmt::Display for Mutant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.op {
+            MutationOp::DeleteMatchArm { arm, .. } => {
+                write!(f, "delete match arm {arm} => .. in {}", self.function_name)
+            }
+            MutationOp::SwapMatchArms { first, second, .. } => write!(
+                f,
+                "swap match arms {first} => .. and {second} => .. in {}",
+                self.function_name
+            ),
+            _ => write!(
+                f,
+                "replace {} {} with {}",
+                self.function_name,
+                self.return_type,
+                self.op.replacement()
+            ),
+        }
+    }
+}
+
+/// How to mutate the value a function (or match arm) produces.
+///
+/// Most variants are fixed replacements; [`MutationOp::Literal`] carries an
+/// arbitrary replacement expression, and [`MutationOp::Wrapped`] and
+/// [`MutationOp::DeleteMatchArm`] compose other ops so that the recursive
+/// return-type handling in [`crate::visit`] can reuse the fixed renderings.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MutationOp {
+    /// Replace a `()`-returning body with `()`.
+    Unit,
+    /// Replace with `Default::default()`.
+    Default,
+    /// Replace with `true`.
+    True,
+    /// Replace with `false`.
+    False,
+    /// Replace with `String::new()`.
+    EmptyString,
+    /// Replace with `"xyzzy".into()`.
+    Xyzzy,
+    /// Replace with `Ok(Default::default())`.
+    OkDefault,
+    /// Splice the carried source text verbatim, e.g. `""`, `vec![]`,
+    /// `<u32>::MAX`, or `None`.
+    Literal(String),
+    /// Render `inner`'s replacement, then splice `{open}<inner>{close}`,
+    /// e.g. `Ok(` + `true` + `)`.
+    Wrapped {
+        open: String,
+        inner: Box<MutationOp>,
+        close: String,
+    },
+    /// Replace a match arm body with `default`; `arm` is the pattern text, used
+    /// only for the descriptive mutant name.
+    DeleteMatchArm {
+        arm: String,
+        default: Box<MutationOp>,
+    },
+    /// Replace a whole `match` expression with one whose two named arm bodies
+    /// are swapped. `replacement` is the rewritten match source; `first`/
+    /// `second` are the pattern texts, used only for the mutant name.
+    SwapMatchArms {
+        first: String,
+        second: String,
+        replacement: String,
+    },
+}
+
+impl MutationOp {
+    /// The replacement source text this op lowers to.
+    pub fn replacement(&self) -> String {
+        match self {
+            MutationOp::Unit => "()".to_owned(),
+            MutationOp::Default => "Default::default()".to_owned(),
+            MutationOp::True => "true".to_owned(),
+            MutationOp::False => "false".to_owned(),
+            MutationOp::EmptyString => "String::new()".to_owned(),
+            MutationOp::Xyzzy => "\"xyzzy\".into()".to_owned(),
+            MutationOp::OkDefault => "Ok(Default::default())".to_owned(),
+            MutationOp::Literal(text) => text.clone(),
+            MutationOp::Wrapped { open, inner, close } => {
+                format!("{open}{}{close}", inner.replacement())
+            }
+            MutationOp::DeleteMatchArm { default, .. } => default.replacement(),
+            MutationOp::SwapMatchArms { replacement, .. } => replacement.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MutationOp;
+
+    #[test]
+    fn wrapped_composes_inner_replacement() {
+        let op = MutationOp::Wrapped {
+            open: "Ok(".to_owned(),
+            inner: Box::new(MutationOp::True),
+            close: ")".to_owned(),
+        };
+        assert_eq!(op.replacement(), "Ok(true)");
+    }
+}
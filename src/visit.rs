@@ -7,19 +7,66 @@
 use std::sync::Arc;
 
 use quote::ToTokens;
+use syn::spanned::Spanned;
 use syn::visit::Visit;
 use syn::Attribute;
 use syn::ItemFn;
 
 use crate::*;
 
-/// Find all possible mutants in a source file.
+/// Which numeric boundary values to generate for integer- and float-returning
+/// functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericBoundaries {
+    /// Only the `0` and `1` replacements.
+    ZeroOne,
+    /// `0`, `1`, each type's `MAX`/`MIN`, and `-1` for signed types.
+    Extremes,
+}
+
+impl Default for NumericBoundaries {
+    fn default() -> Self {
+        NumericBoundaries::Extremes
+    }
+}
+
+impl NumericBoundaries {
+    /// Map a "generate extreme numeric values" toggle to a boundary set.
+    ///
+    /// `true` selects [`NumericBoundaries::Extremes`] (the default set), and
+    /// `false` opts down to just `{0, 1}`.
+    pub fn from_extremes(extremes: bool) -> Self {
+        if extremes {
+            NumericBoundaries::Extremes
+        } else {
+            NumericBoundaries::ZeroOne
+        }
+    }
+}
+
+/// Find all possible mutants in a source file, using the default numeric
+/// boundary set.
+///
+/// This keeps the original signature so existing callers don't need to change;
+/// callers that expose the numeric-boundary option to the user should call
+/// [`discover_mutants_with`] with the value derived from their `Options`.
 pub fn discover_mutants(source_file: Arc<SourceFile>) -> Result<Vec<Mutant>> {
+    discover_mutants_with(source_file, NumericBoundaries::default())
+}
+
+/// Find all possible mutants in a source file with an explicit numeric
+/// boundary set.
+pub fn discover_mutants_with(
+    source_file: Arc<SourceFile>,
+    numeric_boundaries: NumericBoundaries,
+) -> Result<Vec<Mutant>> {
     let syn_file = syn::parse_str::<syn::File>(&source_file.code)?;
     let mut visitor = DiscoveryVisitor {
         source_file,
         mutants: Vec::new(),
         namespace_stack: Vec::new(),
+        return_type_stack: Vec::new(),
+        numeric_boundaries,
     };
     visitor.visit_file(&syn_file);
     Ok(visitor.mutants)
@@ -36,13 +83,32 @@ struct DiscoveryVisitor {
 
     /// The stack of namespaces we're currently inside.
     namespace_stack: Vec<String>,
+
+    /// The stack of return types of the functions we're currently inside.
+    ///
+    /// The innermost function's return type is the last element; it's used to
+    /// pick replacement values for mutations found inside a function body, such
+    /// as deleted match arms.
+    return_type_stack: Vec<syn::ReturnType>,
+
+    /// Which numeric boundary values to generate for numeric return types.
+    numeric_boundaries: NumericBoundaries,
 }
 
+// The recursive/composed return-type handling below uses the `Literal`,
+// `Wrapped`, and `DeleteMatchArm` variants of `MutationOp`; they and their
+// replacement rendering are defined in `crate::mutant`.
+
+/// How deep `ops_for_return_type` will recurse into wrapping types such as
+/// `Result<T, E>` before giving up. This stops us looping forever on
+/// pathological types like `Result<Result<Result<...>>>`.
+const MAX_RETURN_TYPE_DEPTH: usize = 4;
+
 impl DiscoveryVisitor {
     fn collect_fn_mutants(&mut self, return_type: &syn::ReturnType, span: &proc_macro2::Span) {
         let full_function_name = Arc::new(self.namespace_stack.join("::"));
         let return_type_str = Arc::new(return_type_to_string(return_type));
-        for op in ops_for_return_type(return_type) {
+        for op in ops_for_return_type(return_type, 0, self.numeric_boundaries) {
             self.mutants.push(Mutant::new(
                 self.source_file.clone(),
                 op,
@@ -53,6 +119,78 @@ impl DiscoveryVisitor {
         }
     }
 
+    /// Generate finer-grained mutants for the `match` expression in the
+    /// function's tail (return) position, if any.
+    ///
+    /// We only look at a tail match because its arm bodies all have the
+    /// function's return type, so the replacements we emit type-check. Matches
+    /// in statement position would need the arm's own (often `()`) type, and
+    /// replacing them with the return-type default produces swathes of
+    /// unviable mutants.
+    ///
+    /// For each arm we emit a mutant that replaces the arm *body* with the
+    /// return-type default, so tests that never exercise that arm survive; and
+    /// for each adjacent pair of arms we emit a mutant that swaps their bodies.
+    /// Arms whose body is `unreachable!()`/`todo!()` are skipped.
+    fn collect_tail_match_mutants(&mut self, block: &syn::Block) {
+        let expr_match = match tail_match(block) {
+            Some(expr_match) if !attrs_excluded(&expr_match.attrs) && expr_match.arms.len() > 1 => {
+                expr_match
+            }
+            _ => return,
+        };
+        let full_function_name = Arc::new(self.namespace_stack.join("::"));
+        let return_type = self.return_type_stack.last().cloned().unwrap_or_default();
+        let return_type_str = Arc::new(return_type_to_string(&return_type));
+        let default_op = ops_for_return_type(&return_type, 0, self.numeric_boundaries)
+            .into_iter()
+            .next()
+            .unwrap_or(MutationOp::Default);
+        for arm in &expr_match.arms {
+            if attrs_excluded(&arm.attrs) || arm_body_is_trivial(&arm.body) {
+                continue;
+            }
+            let arm_pat = remove_excess_spaces(&arm.pat.to_token_stream().to_string());
+            self.mutants.push(Mutant::new(
+                self.source_file.clone(),
+                MutationOp::DeleteMatchArm {
+                    arm: arm_pat,
+                    default: Box::new(default_op.clone()),
+                },
+                full_function_name.clone(),
+                return_type_str.clone(),
+                // Replace the arm body, not the `=>` token, so the patch stays
+                // syntactically valid (cf. `collect_fn_mutants` spanning the
+                // whole function body).
+                (&arm.body.span()).into(),
+            ));
+        }
+        for (index, pair) in expr_match.arms.windows(2).enumerate() {
+            if pair
+                .iter()
+                .any(|arm| attrs_excluded(&arm.attrs) || arm_body_is_trivial(&arm.body))
+            {
+                continue;
+            }
+            let first = remove_excess_spaces(&pair[0].pat.to_token_stream().to_string());
+            let second = remove_excess_spaces(&pair[1].pat.to_token_stream().to_string());
+            self.mutants.push(Mutant::new(
+                self.source_file.clone(),
+                MutationOp::SwapMatchArms {
+                    first,
+                    second,
+                    // Swapping two arm bodies edits two disjoint regions, which
+                    // a single span can't cover, so replace the whole match with
+                    // a rewritten copy.
+                    replacement: swapped_match_source(expr_match, index),
+                },
+                full_function_name.clone(),
+                return_type_str.clone(),
+                (&expr_match.span()).into(),
+            ));
+        }
+    }
+
     /// Call a function with a namespace pushed onto the stack.
     ///
     /// This is used when recursively descending into a namespace.
@@ -76,7 +214,10 @@ impl<'ast> Visit<'ast> for DiscoveryVisitor {
         let function_name = remove_excess_spaces(&i.sig.ident.to_token_stream().to_string());
         self.in_namespace(&function_name, |self_| {
             self_.collect_fn_mutants(&i.sig.output, &i.block.brace_token.span);
+            self_.return_type_stack.push(i.sig.output.clone());
+            self_.collect_tail_match_mutants(&i.block);
             syn::visit::visit_item_fn(self_, i);
+            self_.return_type_stack.pop();
         });
     }
 
@@ -90,7 +231,10 @@ impl<'ast> Visit<'ast> for DiscoveryVisitor {
         let function_name = remove_excess_spaces(&i.sig.ident.to_token_stream().to_string());
         self.in_namespace(&function_name, |self_| {
             self_.collect_fn_mutants(&i.sig.output, &i.block.brace_token.span);
-            syn::visit::visit_impl_item_method(self_, i)
+            self_.return_type_stack.push(i.sig.output.clone());
+            self_.collect_tail_match_mutants(&i.block);
+            syn::visit::visit_impl_item_method(self_, i);
+            self_.return_type_stack.pop();
         });
     }
 
@@ -131,34 +275,279 @@ impl<'ast> Visit<'ast> for DiscoveryVisitor {
     }
 }
 
-fn ops_for_return_type(return_type: &syn::ReturnType) -> Vec<MutationOp> {
+fn ops_for_return_type(
+    return_type: &syn::ReturnType,
+    depth: usize,
+    boundaries: NumericBoundaries,
+) -> Vec<MutationOp> {
     let mut ops: Vec<MutationOp> = Vec::new();
     match return_type {
         syn::ReturnType::Default => ops.push(MutationOp::Unit),
-        syn::ReturnType::Type(_rarrow, box_typ) => match &**box_typ {
-            syn::Type::Path(syn::TypePath { path, .. }) => {
-                // dbg!(&path);
-                if path.is_ident("bool") {
-                    ops.push(MutationOp::True);
-                    ops.push(MutationOp::False);
-                } else if path.is_ident("String") {
-                    // TODO: Detect &str etc.
-                    ops.push(MutationOp::EmptyString);
-                    ops.push(MutationOp::Xyzzy);
-                } else if path_is_result(path) {
-                    // TODO: Try this for any path ending in "Result".
-                    // TODO: Recursively generate for types inside the Ok side of the Result.
-                    ops.push(MutationOp::OkDefault);
-                } else {
-                    ops.push(MutationOp::Default)
+        syn::ReturnType::Type(_rarrow, box_typ) => {
+            // Callable returns (`impl Fn`, `Box<dyn Fn>`, bare `fn`) get a
+            // synthesized replacement closure. Detected once, up front.
+            if let Some(sig) = fn_like_signature(box_typ) {
+                ops.extend(closure_ops(&sig, depth, boundaries));
+            } else {
+                match &**box_typ {
+                    syn::Type::Path(syn::TypePath { path, .. }) => {
+                        // dbg!(&path);
+                        if path.is_ident("bool") {
+                            ops.push(MutationOp::True);
+                            ops.push(MutationOp::False);
+                        } else if path.is_ident("String") {
+                            // `&str` is handled in the `Type::Reference` arm below.
+                            ops.push(MutationOp::EmptyString);
+                            ops.push(MutationOp::Xyzzy);
+                        } else if path_is_result(path) {
+                            ops.extend(ok_ops(path, depth, boundaries));
+                        } else if path_is_option(path) {
+                            ops.extend(option_ops(path, depth, boundaries));
+                        } else if path_is_vec(path) {
+                            ops.extend(collection_ops("vec![", "]"));
+                        } else if let Some(numeric) = numeric_ops(path, boundaries) {
+                            ops.extend(numeric);
+                        } else {
+                            ops.push(MutationOp::Default)
+                        }
+                    }
+                    syn::Type::Reference(syn::TypeReference { elem, .. }) => match &**elem {
+                        // `&str` behaves like `String`, but the replacements are
+                        // bare string literals rather than owned `String`s.
+                        syn::Type::Path(syn::TypePath { path, .. }) if path.is_ident("str") => {
+                            ops.push(MutationOp::Literal("\"\"".to_owned()));
+                            ops.push(MutationOp::Literal("\"xyzzy\"".to_owned()));
+                        }
+                        // `&[T]` — only the empty slice: `&[]` promotes to
+                        // `'static`, but `&[Default::default()]` would borrow a
+                        // temporary and fail to return.
+                        syn::Type::Slice(_) => ops.push(MutationOp::Literal("&[]".to_owned())),
+                        _ => ops.push(MutationOp::Default),
+                    },
+                    // Fixed-size `[T; N]` arrays are skipped: `[]`/
+                    // `[Default::default()]` are `[_; 0]`/`[_; 1]` and almost
+                    // never match `N`, so they'd be unviable.
+                    _ => ops.push(MutationOp::Default),
                 }
             }
-            _ => ops.push(MutationOp::Default),
-        },
+        }
     }
     ops
 }
 
+/// A callable return type we know how to replace with a synthesized closure.
+struct FnLikeSignature {
+    /// Number of arguments the callable takes.
+    arity: usize,
+    /// The callable's own return type.
+    output: syn::ReturnType,
+    /// True when the callable is returned behind a `Box<…>`, so the closure
+    /// must be wrapped in `Box::new(..)` to type-check.
+    boxed: bool,
+}
+
+/// If `ty` is a callable return type — `impl Fn(..) -> B`, `dyn Fn`,
+/// `Box<dyn Fn(..) -> B>`, or a bare `fn(..) -> B` pointer — return its arity
+/// and output type so we can synthesize a replacement closure for it.
+fn fn_like_signature(ty: &syn::Type) -> Option<FnLikeSignature> {
+    match ty {
+        syn::Type::ImplTrait(syn::TypeImplTrait { bounds, .. }) => {
+            fn_bound_signature(bounds).map(|(arity, output)| FnLikeSignature {
+                arity,
+                output,
+                boxed: false,
+            })
+        }
+        syn::Type::TraitObject(syn::TypeTraitObject { bounds, .. }) => {
+            fn_bound_signature(bounds).map(|(arity, output)| FnLikeSignature {
+                arity,
+                output,
+                boxed: false,
+            })
+        }
+        syn::Type::BareFn(bare) => Some(FnLikeSignature {
+            arity: bare.inputs.len(),
+            output: bare.output.clone(),
+            boxed: false,
+        }),
+        // `Box<dyn Fn(..) -> B>`: look at the wrapped type, and remember that
+        // the replacement closure must itself be boxed.
+        syn::Type::Path(syn::TypePath { path, .. }) => {
+            if path.segments.last()?.ident != "Box" {
+                return None;
+            }
+            first_generic_argument(path)
+                .and_then(fn_like_signature)
+                .map(|sig| FnLikeSignature {
+                    boxed: true,
+                    ..sig
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Find a `Fn`/`FnMut`/`FnOnce` bound among `bounds` and read its arity and
+/// output type from the parenthesized arguments.
+fn fn_bound_signature(
+    bounds: &syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>,
+) -> Option<(usize, syn::ReturnType)> {
+    bounds.iter().find_map(|bound| match bound {
+        syn::TypeParamBound::Trait(trait_bound) => {
+            let segment = trait_bound.path.segments.last()?;
+            if !(segment.ident == "Fn" || segment.ident == "FnMut" || segment.ident == "FnOnce") {
+                return None;
+            }
+            match &segment.arguments {
+                syn::PathArguments::Parenthesized(args) => {
+                    Some((args.inputs.len(), args.output.clone()))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// Synthesize replacement closures that ignore their arguments and return a
+/// default-ish value for the closure's own return type, e.g. `|_, _| true` for
+/// an `impl Fn(u8, u8) -> bool`.
+fn closure_ops(sig: &FnLikeSignature, depth: usize, boundaries: NumericBoundaries) -> Vec<MutationOp> {
+    let args = vec!["_"; sig.arity].join(", ");
+    let prefix = format!("|{args}| ");
+    let body_ops = if depth < MAX_RETURN_TYPE_DEPTH {
+        ops_for_return_type(&sig.output, depth + 1, boundaries)
+    } else {
+        Vec::new()
+    };
+    let closures = if body_ops.is_empty() {
+        vec![MutationOp::Literal(format!("{prefix}Default::default()"))]
+    } else {
+        wrap_ops(&prefix, "", body_ops)
+    };
+    // A boxed callable (`Box<dyn Fn..>`) needs the closure boxed to type-check.
+    if sig.boxed {
+        wrap_ops("Box::new(", ")", closures)
+    } else {
+        closures
+    }
+}
+
+/// Boundary-value replacements for a primitive integer or float return type.
+///
+/// Returns `None` if `path` isn't a recognized numeric primitive. The set of
+/// boundaries is controlled by `boundaries`: `ZeroOne` keeps only `0` and `1`,
+/// while `Extremes` also adds each type's `MAX`/`MIN` (and `-1` for signed
+/// types), catching off-by-one and boundary bugs.
+fn numeric_ops(path: &syn::Path, boundaries: NumericBoundaries) -> Option<Vec<MutationOp>> {
+    const SIGNED: &[&str] = &["i8", "i16", "i32", "i64", "i128", "isize"];
+    const UNSIGNED: &[&str] = &["u8", "u16", "u32", "u64", "u128", "usize"];
+    const FLOAT: &[&str] = &["f32", "f64"];
+
+    let ident = path.get_ident()?.to_string();
+    let ty = ident.as_str();
+    let signed = SIGNED.contains(&ty);
+    let float = FLOAT.contains(&ty);
+    if !(signed || float || UNSIGNED.contains(&ty)) {
+        return None;
+    }
+
+    // Suffix the literals with the type so they compile unambiguously.
+    let (zero, one, minus_one) = if float {
+        (format!("0.0{ty}"), format!("1.0{ty}"), format!("-1.0{ty}"))
+    } else {
+        (format!("0{ty}"), format!("1{ty}"), format!("-1{ty}"))
+    };
+    let mut literals = vec![zero, one];
+    if boundaries == NumericBoundaries::Extremes {
+        if signed || float {
+            literals.push(minus_one);
+        }
+        literals.push(format!("<{ty}>::MAX"));
+        literals.push(format!("<{ty}>::MIN"));
+    }
+    Some(literals.into_iter().map(MutationOp::Literal).collect())
+}
+
+/// The empty and single-element replacements for a collection return type.
+///
+/// A single-element container is the minimal interesting non-empty case, so
+/// code that conflates "empty" with "one element" gets caught.
+fn collection_ops(open: &str, close: &str) -> Vec<MutationOp> {
+    vec![
+        MutationOp::Literal(format!("{open}{close}")),
+        MutationOp::Literal(format!("{open}Default::default(){close}")),
+    ]
+}
+
+/// Generate the `Ok(...)` family of mutations for a `Result`-returning
+/// function.
+///
+/// We recurse into the `Ok` side of the `Result` and wrap each replacement we
+/// find there in `Ok(..)`, so that `Result<bool, E>` yields `Ok(true)` and
+/// `Ok(false)`. If we can't extract an inner type, or we've recursed too
+/// deeply, fall back to the plain `Ok(Default::default())` mutant.
+fn ok_ops(path: &syn::Path, depth: usize, boundaries: NumericBoundaries) -> Vec<MutationOp> {
+    if depth < MAX_RETURN_TYPE_DEPTH {
+        if let Some(inner) = first_generic_argument(path) {
+            let inner_ops =
+                ops_for_return_type(&synthetic_return_type(inner), depth + 1, boundaries);
+            if !inner_ops.is_empty() {
+                return wrap_ops("Ok(", ")", inner_ops);
+            }
+        }
+    }
+    vec![MutationOp::OkDefault]
+}
+
+/// Generate the `None` / `Some(..)` family of mutations for an
+/// `Option`-returning function.
+///
+/// `None` exercises code that never checks for the absent case, and the
+/// recursively-generated `Some(<inner>)` family catches tests that never
+/// assert on the contained value.
+fn option_ops(path: &syn::Path, depth: usize, boundaries: NumericBoundaries) -> Vec<MutationOp> {
+    let mut ops = vec![MutationOp::Literal("None".to_owned())];
+    if depth < MAX_RETURN_TYPE_DEPTH {
+        if let Some(inner) = first_generic_argument(path) {
+            let inner_ops =
+                ops_for_return_type(&synthetic_return_type(inner), depth + 1, boundaries);
+            ops.extend(wrap_ops("Some(", ")", inner_ops));
+        }
+    }
+    ops
+}
+
+/// Extract the first `Type` generic argument of a path's last segment, as in
+/// the `T` of `Result<T, E>` or `anyhow::Result<T>`.
+fn first_generic_argument(path: &syn::Path) -> Option<&syn::Type> {
+    match &path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Build a `ReturnType::Type` wrapping `ty`, so it can be fed back into
+/// [`ops_for_return_type`] for recursive generation.
+fn synthetic_return_type(ty: &syn::Type) -> syn::ReturnType {
+    syn::ReturnType::Type(Default::default(), Box::new(ty.clone()))
+}
+
+/// Wrap every op in `inner` so its replacement becomes `{open}<inner>{close}`.
+fn wrap_ops(open: &str, close: &str, inner: Vec<MutationOp>) -> Vec<MutationOp> {
+    inner
+        .into_iter()
+        .map(|op| MutationOp::Wrapped {
+            open: open.to_owned(),
+            inner: Box::new(op),
+            close: close.to_owned(),
+        })
+        .collect()
+}
+
 fn type_name_string(ty: &syn::Type) -> String {
     ty.to_token_stream().to_string()
 }
@@ -220,6 +609,20 @@ fn path_is_result(path: &syn::Path) -> bool {
         .unwrap_or_default()
 }
 
+fn path_is_option(path: &syn::Path) -> bool {
+    path.segments
+        .last()
+        .map(|segment| segment.ident == "Option")
+        .unwrap_or_default()
+}
+
+fn path_is_vec(path: &syn::Path) -> bool {
+    path.segments
+        .last()
+        .map(|segment| segment.ident == "Vec")
+        .unwrap_or_default()
+}
+
 /// True if any of the attrs indicate that we should skip this node and everything inside it.
 fn attrs_excluded(attrs: &[Attribute]) -> bool {
     attrs
@@ -227,6 +630,41 @@ fn attrs_excluded(attrs: &[Attribute]) -> bool {
         .any(|attr| attr_is_cfg_test(attr) || attr_is_test(attr) || attr_is_mutants_skip(attr))
 }
 
+/// The `match` expression in the function body's tail/return position, if the
+/// body ends in one.
+fn tail_match(block: &syn::Block) -> Option<&syn::ExprMatch> {
+    match block.stmts.last()? {
+        syn::Stmt::Expr(syn::Expr::Match(expr_match)) => Some(expr_match),
+        syn::Stmt::Semi(syn::Expr::Return(syn::ExprReturn { expr: Some(expr), .. }), _) => {
+            match &**expr {
+                syn::Expr::Match(expr_match) => Some(expr_match),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Render a copy of `expr_match` with the bodies of arms `index` and `index+1`
+/// swapped.
+fn swapped_match_source(expr_match: &syn::ExprMatch, index: usize) -> String {
+    let mut swapped = expr_match.clone();
+    let (left, right) = swapped.arms.split_at_mut(index + 1);
+    std::mem::swap(&mut left[index].body, &mut right[0].body);
+    remove_excess_spaces(&swapped.to_token_stream().to_string())
+}
+
+/// True if a match arm's body is a `todo!()`/`unimplemented!()`/`unreachable!()`
+/// macro call, which we don't bother mutating.
+fn arm_body_is_trivial(body: &syn::Expr) -> bool {
+    if let syn::Expr::Macro(syn::ExprMacro { mac, .. }) = body {
+        if let Some(ident) = mac.path.segments.last().map(|s| &s.ident) {
+            return ident == "todo" || ident == "unimplemented" || ident == "unreachable";
+        }
+    }
+    false
+}
+
 /// True if the block (e.g. the contents of a function) is empty.
 fn block_is_empty(block: &syn::Block) -> bool {
     block.stmts.is_empty()
@@ -267,9 +705,81 @@ fn attr_is_mutants_skip(attr: &Attribute) -> bool {
 
 #[cfg(test)]
 mod test {
+    use super::NumericBoundaries;
+
+    /// The replacement source each op generated for `return_type` lowers to.
+    fn replacements(return_type: syn::ReturnType) -> Vec<String> {
+        super::ops_for_return_type(&return_type, 0, NumericBoundaries::Extremes)
+            .iter()
+            .map(|op| op.replacement())
+            .collect()
+    }
+
     #[test]
     fn path_is_result() {
         let path: syn::Path = syn::parse_quote! { Result<(), ()> };
         assert!(super::path_is_result(&path));
     }
+
+    #[test]
+    fn path_is_option() {
+        let path: syn::Path = syn::parse_quote! { Option<String> };
+        assert!(super::path_is_option(&path));
+    }
+
+    #[test]
+    fn option_return_generates_none_and_some_family() {
+        let reps = replacements(syn::parse_quote! { -> Option<bool> });
+        assert!(reps.contains(&"None".to_owned()));
+        assert!(reps.contains(&"Some(true)".to_owned()));
+        assert!(reps.contains(&"Some(false)".to_owned()));
+    }
+
+    #[test]
+    fn str_and_vec_returns_lower_to_literals() {
+        assert_eq!(
+            replacements(syn::parse_quote! { -> &str }),
+            vec!["\"\"".to_owned(), "\"xyzzy\"".to_owned()]
+        );
+        assert_eq!(
+            replacements(syn::parse_quote! { -> Vec<u8> }),
+            vec!["vec![]".to_owned(), "vec![Default::default()]".to_owned()]
+        );
+    }
+
+    #[test]
+    fn tail_match_arms_get_delete_and_swap_mutants() {
+        use std::sync::Arc;
+        let code = "fn classify(n: u8) -> bool { match n { 0 => false, _ => true } }";
+        let source = Arc::new(crate::SourceFile {
+            code: code.to_owned(),
+        });
+        let names: Vec<String> = super::discover_mutants(source)
+            .unwrap()
+            .iter()
+            .map(|mutant| mutant.to_string())
+            .collect();
+        assert!(names.iter().any(|n| n.starts_with("delete match arm")));
+        assert!(names.iter().any(|n| n.starts_with("swap match arms")));
+    }
+
+    #[test]
+    fn numeric_ops_can_be_trimmed_to_zero_and_one() {
+        let path: syn::Path = syn::parse_quote! { u32 };
+        let zero_one = super::numeric_ops(&path, NumericBoundaries::ZeroOne).unwrap();
+        assert_eq!(
+            zero_one.iter().map(|op| op.replacement()).collect::<Vec<_>>(),
+            vec!["0u32".to_owned(), "1u32".to_owned()]
+        );
+        let extremes = super::numeric_ops(&path, NumericBoundaries::Extremes).unwrap();
+        assert!(extremes.len() > zero_one.len());
+    }
+
+    #[test]
+    fn signed_extremes_include_minus_one_and_min_max() {
+        let reps = replacements(syn::parse_quote! { -> i64 });
+        assert!(reps.contains(&"-1i64".to_owned()));
+        assert!(reps.contains(&"<i64>::MAX".to_owned()));
+        assert!(reps.contains(&"<i64>::MIN".to_owned()));
+    }
 }